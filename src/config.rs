@@ -0,0 +1,360 @@
+use log::{Level, LevelFilter};
+use web_sys::console;
+
+#[cfg(feature = "color")]
+use crate::style::Style;
+
+/// Configuration for the logger.
+///
+/// A `Config` is built up with [`Builder`] and installed with [`Builder::init`] (or one of the
+/// [`init`](crate::init)/[`init_with_level`](crate::init_with_level) shortcuts). It controls which
+/// records are passed through to the browser console and how they're rendered.
+pub struct Config {
+    pub(crate) level: LevelFilter,
+    pub(crate) module_levels: Vec<(String, LevelFilter)>,
+    #[cfg(feature = "color")]
+    pub(crate) style: Style,
+    pub(crate) show_target: bool,
+    pub(crate) show_file_line: bool,
+    pub(crate) message_location: MessageLocation,
+    pub(crate) show_timestamp: bool,
+    pub(crate) show_session_id: bool,
+    pub(crate) session_id: String,
+}
+
+/// Controls where the message body is placed relative to the level/location prefix.
+pub enum MessageLocation {
+    /// The message follows the prefix on the same line.
+    SameLine,
+    /// The message starts on its own line below the prefix.
+    NewLine,
+}
+
+impl Config {
+    /// The most permissive level enabled by this config, taking per-target overrides into
+    /// account. Used to drive `log::set_max_level` so the `log` facade doesn't filter out records
+    /// this config would otherwise let through.
+    pub(crate) fn max_level(&self) -> LevelFilter {
+        self.module_levels
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.level, std::cmp::max)
+    }
+
+    /// The level enabled for the given target, taking the longest matching per-target prefix
+    /// override into account and falling back to the default level.
+    pub(crate) fn level_for(&self, target: &str) -> LevelFilter {
+        self.module_levels
+            .iter()
+            .find(|(prefix, _)| target.starts_with(prefix.as_str()))
+            .map(|(_, level)| *level)
+            .unwrap_or(self.level)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            level: Level::Info.to_level_filter(),
+            module_levels: Vec::new(),
+            #[cfg(feature = "color")]
+            style: Style::default(),
+            show_target: false,
+            // the color path has always rendered `file:line`; the plain path never has, so keep
+            // both defaults matching prior behavior
+            #[cfg(feature = "color")]
+            show_file_line: true,
+            #[cfg(not(feature = "color"))]
+            show_file_line: false,
+            message_location: MessageLocation::NewLine,
+            show_timestamp: false,
+            show_session_id: false,
+            session_id: String::new(),
+        }
+    }
+}
+
+/// Warns (via `console.warn`) that a directive passed to [`Builder::parse`] didn't parse as a
+/// target=level pair or a bare [`LevelFilter`], and was therefore skipped.
+fn warn_unparseable_directive(directive: &str) {
+    console::warn_1(
+        &format!("console_log: ignoring unparseable log directive {directive:?}").into(),
+    );
+}
+
+/// Generates a short random id to tell apart console output from different page loads/workers.
+/// Not cryptographically secure; this is for disambiguation, not security.
+fn generate_session_id() -> String {
+    const CHARS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    (0..6)
+        .map(|_| {
+            let idx = (js_sys::Math::random() * CHARS.len() as f64) as usize;
+            CHARS[idx.min(CHARS.len() - 1)] as char
+        })
+        .collect()
+}
+
+/// A builder for [`Config`].
+///
+/// ## Example
+///
+/// ```
+/// use log::Level;
+///
+/// console_log::Builder::new()
+///     .with_level(Level::Info)
+///     .with_target_level("my_crate::net", Level::Debug.to_level_filter())
+///     .with_prefix_filter("wgpu")
+///     .init()
+///     .expect("error initializing logger");
+/// ```
+#[derive(Default)]
+pub struct Builder {
+    config: Config,
+}
+
+impl Builder {
+    /// Creates a new builder with the default configuration (`Level::Info`, no per-target
+    /// overrides).
+    pub fn new() -> Self {
+        Builder::default()
+    }
+
+    /// Sets the default level used for targets without a more specific override.
+    pub fn with_level(&mut self, level: Level) -> &mut Self {
+        self.config.level = level.to_level_filter();
+        self
+    }
+
+    /// Overrides the level for a specific target prefix, e.g. `"my_crate::net"`. The longest
+    /// matching prefix wins when a record's target matches more than one override.
+    pub fn with_target_level(
+        &mut self,
+        target: impl Into<String>,
+        level: LevelFilter,
+    ) -> &mut Self {
+        let target = target.into();
+        match self
+            .config
+            .module_levels
+            .iter_mut()
+            .find(|(prefix, _)| *prefix == target)
+        {
+            Some((_, existing)) => *existing = level,
+            None => self.config.module_levels.push((target, level)),
+        }
+        self.config
+            .module_levels
+            .sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+        self
+    }
+
+    /// Silences a noisy target prefix entirely. Shorthand for
+    /// `with_target_level(prefix, LevelFilter::Off)`.
+    pub fn with_prefix_filter(&mut self, prefix: impl Into<String>) -> &mut Self {
+        self.with_target_level(prefix, LevelFilter::Off)
+    }
+
+    /// Parses a comma-separated directive string in the familiar
+    /// `info,my_crate::net=debug,wgpu=off` form, as accepted by `RUST_LOG` and similar. A
+    /// directive without a target sets the default level (the last such directive wins) and every
+    /// other directive is of the form `target=level`; both positions accept a [`LevelFilter`] (so
+    /// `off` works on either side). A directive that doesn't parse is logged as a console warning and otherwise ignored.
+    pub fn parse(&mut self, directives: &str) -> &mut Self {
+        for directive in directives
+            .split(',')
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+        {
+            match directive.split_once('=') {
+                Some((target, level)) => match level.parse() {
+                    Ok(level) => {
+                        self.with_target_level(target, level);
+                    }
+                    Err(_) => warn_unparseable_directive(directive),
+                },
+                None => match directive.parse() {
+                    Ok(level) => self.config.level = level,
+                    Err(_) => warn_unparseable_directive(directive),
+                },
+            }
+        }
+        self
+    }
+
+    /// Overrides the CSS style applied to the level badge (e.g. `INFO`) for the given level. Only
+    /// available with the `"color"` feature.
+    ///
+    /// ## Example
+    ///
+    /// Requires the `"color"` feature, hence `ignore` below since doctests build with default
+    /// features.
+    /// ```rust,ignore
+    /// use log::Level;
+    ///
+    /// console_log::Builder::new()
+    ///     .with_level_style(Level::Error, "color:#fff;background:#900")
+    ///     .init()
+    ///     .expect("error initializing logger");
+    /// ```
+    #[cfg(feature = "color")]
+    pub fn with_level_style(&mut self, level: Level, style: impl Into<String>) -> &mut Self {
+        let style = style.into();
+        match level {
+            Level::Trace => self.config.style.trace = style,
+            Level::Debug => self.config.style.debug = style,
+            Level::Info => self.config.style.info = style,
+            Level::Warn => self.config.style.warn = style,
+            Level::Error => self.config.style.error = style,
+        }
+        self
+    }
+
+    /// Overrides the CSS style applied to the `file:line` segment. Only available with the
+    /// `"color"` feature.
+    #[cfg(feature = "color")]
+    pub fn with_file_line_style(&mut self, style: impl Into<String>) -> &mut Self {
+        self.config.style.file_line = style.into();
+        self
+    }
+
+    /// Overrides the CSS style applied to the message text. Only available with the `"color"`
+    /// feature.
+    #[cfg(feature = "color")]
+    pub fn with_text_style(&mut self, style: impl Into<String>) -> &mut Self {
+        self.config.style.text = style.into();
+        self
+    }
+
+    /// Overrides the CSS style applied to the timestamp segment. Only available with the
+    /// `"color"` feature.
+    #[cfg(feature = "color")]
+    pub fn with_timestamp_style(&mut self, style: impl Into<String>) -> &mut Self {
+        self.config.style.timestamp = style.into();
+        self
+    }
+
+    /// Whether to prefix each record with its target (the logging module path). Defaults to
+    /// `false`.
+    pub fn with_show_target(&mut self, show_target: bool) -> &mut Self {
+        self.config.show_target = show_target;
+        self
+    }
+
+    /// Whether to prefix each record with its `file:line`. With the `"color"` feature this
+    /// defaults to `true`; without it, `false` (matching this crate's historical behavior).
+    pub fn with_show_file_line(&mut self, show_file_line: bool) -> &mut Self {
+        self.config.show_file_line = show_file_line;
+        self
+    }
+
+    /// Controls whether the message body is placed on the same line as the level/location prefix
+    /// or on its own line below it. Defaults to [`MessageLocation::NewLine`].
+    pub fn with_message_location(&mut self, message_location: MessageLocation) -> &mut Self {
+        self.config.message_location = message_location;
+        self
+    }
+
+    /// Prefixes each record with a timestamp (seconds since the Unix epoch, with millisecond
+    /// precision) taken from `js_sys::Date::now()`. Defaults to `false`.
+    pub fn with_timestamp(&mut self, show_timestamp: bool) -> &mut Self {
+        self.config.show_timestamp = show_timestamp;
+        self
+    }
+
+    /// Prefixes each record with a short id that's generated once when [`Builder::init`] runs, so
+    /// console output from a particular page load or worker can be told apart from others.
+    /// Defaults to `false`.
+    pub fn with_session_id(&mut self, show_session_id: bool) -> &mut Self {
+        self.config.show_session_id = show_session_id;
+        self
+    }
+
+    /// Installs this configuration as the global logger.
+    pub fn init(&mut self) -> Result<(), log::SetLoggerError> {
+        if self.config.show_session_id {
+            self.config.session_id = generate_session_id();
+        }
+        let config = std::mem::take(&mut self.config);
+        let max_level = config.max_level();
+        crate::init_with_config(config)?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_for_falls_back_to_default() {
+        let mut builder = Builder::new();
+        builder.with_level(Level::Warn);
+        assert_eq!(builder.config.level_for("my_crate"), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn level_for_picks_longest_matching_prefix() {
+        let mut builder = Builder::new();
+        builder
+            .with_level(Level::Warn)
+            .with_target_level("my_crate", LevelFilter::Info)
+            .with_target_level("my_crate::net", LevelFilter::Debug);
+
+        assert_eq!(
+            builder.config.level_for("my_crate::net::socket"),
+            LevelFilter::Debug
+        );
+        assert_eq!(builder.config.level_for("my_crate::fs"), LevelFilter::Info);
+        assert_eq!(builder.config.level_for("other_crate"), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn max_level_is_the_most_permissive_configured_level() {
+        let mut builder = Builder::new();
+        builder
+            .with_level(Level::Warn)
+            .with_target_level("wgpu", LevelFilter::Off)
+            .with_target_level("my_crate::net", LevelFilter::Trace);
+
+        assert_eq!(builder.config.max_level(), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn parse_sets_the_default_level_filter() {
+        let mut builder = Builder::new();
+        builder.parse("debug");
+        assert_eq!(builder.config.level, LevelFilter::Debug);
+    }
+
+    #[test]
+    fn parse_accepts_off_as_a_bare_default_directive() {
+        // regression test: `off` doesn't parse as a `Level` (it has no `Off` variant), only as a
+        // `LevelFilter`, so a bare `off` directive used to be silently dropped
+        let mut builder = Builder::new();
+        builder.parse("off");
+        assert_eq!(builder.config.level, LevelFilter::Off);
+    }
+
+    #[test]
+    fn parse_handles_default_and_target_directives_together() {
+        let mut builder = Builder::new();
+        builder.parse("off,wgpu=warn");
+        assert_eq!(builder.config.level, LevelFilter::Off);
+        assert_eq!(builder.config.level_for("wgpu"), LevelFilter::Warn);
+        assert_eq!(builder.config.level_for("other_crate"), LevelFilter::Off);
+    }
+
+    #[test]
+    fn parse_prefers_the_longest_target_prefix_directive() {
+        let mut builder = Builder::new();
+        builder.parse("info,my_crate::net=debug,my_crate=warn");
+        assert_eq!(
+            builder.config.level_for("my_crate::net::socket"),
+            LevelFilter::Debug
+        );
+        assert_eq!(builder.config.level_for("my_crate::fs"), LevelFilter::Warn);
+        assert_eq!(builder.config.level_for("other_crate"), LevelFilter::Info);
+    }
+}
@@ -0,0 +1,50 @@
+use js_sys::{Object, Reflect};
+use log::kv::{Error, Key, Value, Visitor};
+use log::Record;
+use wasm_bindgen::JsValue;
+
+struct JsObjectVisitor {
+    object: Object,
+}
+
+impl<'kvs> Visitor<'kvs> for JsObjectVisitor {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+        let _ = Reflect::set(
+            &self.object,
+            &JsValue::from_str(key.as_str()),
+            &js_value(&value),
+        );
+        Ok(())
+    }
+}
+
+/// Converts a `log::kv::Value` to the native JS type it represents (bool, number, string), so
+/// `console.dir` shows an inspectable value rather than a stringified one. Falls back to its
+/// `Display` representation for anything without a native equivalent (e.g. nested structures).
+fn js_value(value: &Value) -> JsValue {
+    if let Some(value) = value.to_bool() {
+        JsValue::from_bool(value)
+    } else if let Some(value) = value.to_f64() {
+        JsValue::from_f64(value)
+    } else if let Some(value) = value.to_borrowed_str() {
+        JsValue::from_str(value)
+    } else {
+        JsValue::from_str(&value.to_string())
+    }
+}
+
+/// Builds a JS object from a record's structured key-value pairs and how many pairs it holds, or
+/// `None` if it has none.
+pub(crate) fn fields(record: &Record) -> Option<(Object, usize)> {
+    let source = record.key_values();
+    let count = source.count();
+    if count == 0 {
+        return None;
+    }
+
+    let mut visitor = JsObjectVisitor {
+        object: Object::new(),
+    };
+    source.visit(&mut visitor).ok()?;
+    Some((visitor.object, count))
+}
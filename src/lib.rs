@@ -29,6 +29,36 @@
 //! | `warn!()`  | `console.warn()`  |
 //! | `error!()` | `console.error()` |
 //!
+//! # Per-Target Filtering
+//!
+//! [`init`] and [`init_with_level`] apply a single level to every target. To silence a noisy
+//! dependency while keeping a more verbose level for your own crate, use [`Builder`] instead:
+//!
+//! ```rust,no_run
+//! use log::{Level, LevelFilter};
+//!
+//! console_log::Builder::new()
+//!     .with_level(Level::Info)
+//!     .with_target_level("my_crate::net", LevelFilter::Debug)
+//!     .with_prefix_filter("wgpu")
+//!     .init()
+//!     .expect("error initializing logger");
+//! ```
+//!
+//! [`Builder::parse`] accepts the same `info,my_crate::net=debug,wgpu=off` directive syntax used
+//! by `RUST_LOG`, which is handy for driving the logger from a query param or build constant.
+//!
+//! # Layout
+//!
+//! [`Builder::with_show_target`] and [`Builder::with_show_file_line`] control whether a record's
+//! target and `file:line` are included in the console output (the non-color path drops both by
+//! default), and [`Builder::with_message_location`] chooses whether the message body shares a line
+//! with that prefix or starts on its own line.
+//!
+//! [`Builder::with_timestamp`] prepends a subsecond-precision timestamp and [`Builder::with_session_id`]
+//! prepends a short id generated once at [`Builder::init`], so output from a particular page load
+//! or worker can be told apart from others without pulling in `fern` + `chrono`.
+//!
 //! # Getting Fancy
 //!
 //! The feature set provided by this crate is intentionally very basic. If you need more flexible
@@ -48,6 +78,38 @@
 //!
 //! ![Styled log messages](img/log_messages_styled.png)
 //!
+//! The colors can be overridden to match your application's theme via [`Builder`] (requires the
+//! `"color"` feature, hence `ignore` below since doctests build with default features):
+//!
+//! ```rust,ignore
+//! use log::Level;
+//!
+//! console_log::Builder::new()
+//!     .with_level_style(Level::Error, "color:#fff;background:#900")
+//!     .init()
+//!     .expect("error initializing logger");
+//! ```
+//!
+//! ## Structured Fields
+//!
+//! The `"kv"` feature renders a record's [structured key-value pairs](https://docs.rs/log/latest/log/kv/index.html)
+//! as an inspectable object in the devtools console (via `console.dir`) instead of ignoring them.
+//!
+//! `Cargo.toml`
+//! ```toml
+//! console_log = { version = "0.2", features = ["kv"] }
+//! log = { version = "0.4", features = ["kv"] }
+//! ```
+//!
+//! ```rust,ignore
+//! use log::info;
+//!
+//! console_log::init().expect("error initializing logger");
+//!
+//! // fields are shown as an expandable object via `console.dir`, not flattened into the text
+//! info!(user_id = 42, action = "login"; "user signed in");
+//! ```
+//!
 //! # Code Size
 //!
 //! [Twiggy] reports this library adding about 180Kb to the size of a minimal wasm binary in a
@@ -98,26 +160,36 @@
 //! [`fern`]: https://docs.rs/fern
 
 use log::{Level, Log, Metadata, Record, SetLoggerError};
+use std::sync::OnceLock;
 use web_sys::console;
 
-#[cfg(feature = "color")]
+#[cfg(any(feature = "color", feature = "kv"))]
 use wasm_bindgen::JsValue;
 
-#[cfg(feature = "color")]
-const STYLE: style::Style<'static> = style::Style::default();
-
 #[cfg(feature = "color")]
 #[doc(hidden)]
 mod style;
 
+#[cfg(feature = "kv")]
+#[doc(hidden)]
+mod kv;
+
+mod config;
+
+pub use config::{Builder, Config, MessageLocation};
+
 static LOGGER: WebConsoleLogger = WebConsoleLogger {};
+static CONFIG: OnceLock<Config> = OnceLock::new();
 
 struct WebConsoleLogger {}
 
 impl Log for WebConsoleLogger {
     #[inline]
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= log::max_level()
+        match CONFIG.get() {
+            Some(config) => metadata.level() <= config.level_for(metadata.target()),
+            None => false,
+        }
     }
 
     fn log(&self, record: &Record) {
@@ -131,6 +203,14 @@ impl Log for WebConsoleLogger {
     fn flush(&self) {}
 }
 
+/// Installs the given [`Config`] as the global logger. Called by [`Builder::init`], which
+/// [`init`] and [`init_with_level`] go through.
+fn init_with_config(config: Config) -> Result<(), SetLoggerError> {
+    log::set_logger(&LOGGER)?;
+    let _ = CONFIG.set(config);
+    Ok(())
+}
+
 /// Print a `log::Record` to the browser's console at the appropriate level.
 ///
 /// This function is useful for integrating with the [`fern`](https://crates.io/crates/fern) logger
@@ -144,6 +224,44 @@ impl Log for WebConsoleLogger {
 /// ```
 #[cfg_attr(not(feature = "color"), inline)]
 pub fn log(record: &Record) {
+    // fall back to the default config so `log()` still works when called directly (e.g. via
+    // `fern::Output::call`) without going through `init`/`Builder`
+    let default_config;
+    let config = match CONFIG.get() {
+        Some(config) => config,
+        None => {
+            default_config = Config::default();
+            &default_config
+        }
+    };
+
+    // the timestamp gets its own prefix segment (and, with the `"color"` feature, its own style)
+    // rather than being folded into `location`, so it stays visually distinct from the
+    // target/file:line that `location` carries
+    let timestamp = config
+        .show_timestamp
+        .then(|| format!("{:.3}", js_sys::Date::now() / 1000.0));
+
+    let location = {
+        let mut location = String::new();
+        if config.show_target {
+            location.push_str(record.target());
+        }
+        if config.show_file_line {
+            if !location.is_empty() {
+                location.push(' ');
+            }
+            location.push_str(&format!(
+                "{file}:{line}",
+                file = record.file().unwrap_or_else(|| record.target()),
+                line = record
+                    .line()
+                    .map_or_else(|| "[Unknown]".to_string(), |line| line.to_string()),
+            ));
+        }
+        location
+    };
+
     #[cfg(not(feature = "color"))]
     {
         // pick the console.log() variant for the appropriate logging level
@@ -155,48 +273,161 @@ pub fn log(record: &Record) {
             Level::Trace => console::debug_1,
         };
 
-        console_log(&format!("{}", record.args()).into());
+        let separator = match config.message_location {
+            MessageLocation::SameLine if location.is_empty() => "",
+            MessageLocation::SameLine => " ",
+            MessageLocation::NewLine => "\n",
+        };
+
+        let session_id = if config.show_session_id {
+            format!("{} ", config.session_id)
+        } else {
+            String::new()
+        };
+
+        let timestamp = match &timestamp {
+            Some(timestamp) => format!("{timestamp} "),
+            None => String::new(),
+        };
+
+        let text = if location.is_empty() {
+            format!("{session_id}{timestamp}{}", record.args())
+        } else {
+            format!(
+                "{session_id}{timestamp}{location}{separator}{text}",
+                text = record.args()
+            )
+        };
+        console_log(&text.into());
     }
 
     #[cfg(feature = "color")]
     {
-        // pick the console.log() variant for the appropriate logging level
-        let console_log = match record.level() {
-            Level::Error => console::error_4,
-            Level::Warn => console::warn_4,
-            Level::Info => console::info_4,
-            Level::Debug => console::log_4,
-            Level::Trace => console::debug_4,
+        let level_style = JsValue::from_str(config.style.level(record.level()));
+        let file_line_style = JsValue::from_str(&config.style.file_line);
+        let text_style = JsValue::from_str(&config.style.text);
+
+        let location = if location.is_empty() {
+            String::new()
+        } else {
+            format!(" {location}")
         };
 
-        let message = {
-            let message = format!(
-                "%c{level}%c {file}:{line} %c\n{text}",
-                level = record.level(),
-                file = record.file().unwrap_or_else(|| record.target()),
-                line = record
-                    .line()
-                    .map_or_else(|| "[Unknown]".to_string(), |line| line.to_string()),
-                text = record.args(),
-            );
-            JsValue::from(&message)
+        // unlike the plain-text path, the level badge is always rendered as its own `%c` segment
+        // here, so there's always something before the message text to separate from, even when
+        // `location` is empty
+        let separator = match config.message_location {
+            MessageLocation::SameLine => " ",
+            MessageLocation::NewLine => "\n",
         };
 
-        let level_style = {
-            let style_str = match record.level() {
-                Level::Trace => STYLE.trace,
-                Level::Debug => STYLE.debug,
-                Level::Info => STYLE.info,
-                Level::Warn => STYLE.warn,
-                Level::Error => STYLE.error,
-            };
+        match (&timestamp, config.show_session_id) {
+            (Some(timestamp), true) => {
+                let console_log = match record.level() {
+                    Level::Error => console::error_6,
+                    Level::Warn => console::warn_6,
+                    Level::Info => console::info_6,
+                    Level::Debug => console::log_6,
+                    Level::Trace => console::debug_6,
+                };
 
-            JsValue::from(style_str)
-        };
+                let message = JsValue::from(&format!(
+                    "%c{timestamp} %c{session_id}%c{level}%c{location}%c{separator}{text}",
+                    session_id = config.session_id,
+                    level = record.level(),
+                    text = record.args(),
+                ));
+                let timestamp_style = JsValue::from_str(&config.style.timestamp);
+                let session_id_style = JsValue::from_str(&config.style.session_id);
+                console_log(
+                    &message,
+                    &timestamp_style,
+                    &session_id_style,
+                    &level_style,
+                    &file_line_style,
+                    &text_style,
+                );
+            }
+            (Some(timestamp), false) => {
+                let console_log = match record.level() {
+                    Level::Error => console::error_5,
+                    Level::Warn => console::warn_5,
+                    Level::Info => console::info_5,
+                    Level::Debug => console::log_5,
+                    Level::Trace => console::debug_5,
+                };
 
-        let file_line_style = JsValue::from_str(STYLE.file_line);
-        let text_style = JsValue::from_str(STYLE.text);
-        console_log(&message, &level_style, &file_line_style, &text_style);
+                let message = JsValue::from(&format!(
+                    "%c{timestamp} %c{level}%c{location}%c{separator}{text}",
+                    level = record.level(),
+                    text = record.args(),
+                ));
+                let timestamp_style = JsValue::from_str(&config.style.timestamp);
+                console_log(
+                    &message,
+                    &timestamp_style,
+                    &level_style,
+                    &file_line_style,
+                    &text_style,
+                );
+            }
+            (None, true) => {
+                let console_log = match record.level() {
+                    Level::Error => console::error_5,
+                    Level::Warn => console::warn_5,
+                    Level::Info => console::info_5,
+                    Level::Debug => console::log_5,
+                    Level::Trace => console::debug_5,
+                };
+
+                let message = JsValue::from(&format!(
+                    "%c{session_id}%c{level}%c{location}%c{separator}{text}",
+                    session_id = config.session_id,
+                    level = record.level(),
+                    text = record.args(),
+                ));
+                let session_id_style = JsValue::from_str(&config.style.session_id);
+                console_log(
+                    &message,
+                    &session_id_style,
+                    &level_style,
+                    &file_line_style,
+                    &text_style,
+                );
+            }
+            (None, false) => {
+                let console_log = match record.level() {
+                    Level::Error => console::error_4,
+                    Level::Warn => console::warn_4,
+                    Level::Info => console::info_4,
+                    Level::Debug => console::log_4,
+                    Level::Trace => console::debug_4,
+                };
+
+                let message = JsValue::from(&format!(
+                    "%c{level}%c{location}%c{separator}{text}",
+                    level = record.level(),
+                    text = record.args(),
+                ));
+                console_log(&message, &level_style, &file_line_style, &text_style);
+            }
+        }
+    }
+
+    // if the record carries structured key-value pairs, render them as an inspectable object
+    // rather than flattening them into the message text; a single field is shown inline, while
+    // multiple fields are tucked into a collapsed group so they don't crowd the message above them
+    #[cfg(feature = "kv")]
+    {
+        if let Some((fields, count)) = kv::fields(record) {
+            if count > 1 {
+                console::group_collapsed_1(&JsValue::from_str(&format!("{count} fields")));
+                console::dir_1(&fields.into());
+                console::group_end();
+            } else {
+                console::dir_1(&fields.into());
+            }
+        }
     }
 }
 
@@ -212,9 +443,7 @@ pub fn log(record: &Record) {
 /// ```
 #[inline]
 pub fn init_with_level(level: Level) -> Result<(), SetLoggerError> {
-    log::set_logger(&LOGGER)?;
-    log::set_max_level(level.to_level_filter());
-    Ok(())
+    Builder::new().with_level(level).init()
 }
 
 /// Initializes the global logger with `max_log_level` set to `Level::Info` (a sensible default).
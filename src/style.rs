@@ -1,24 +1,39 @@
 /// Log message styling.
 ///
 /// Adapted from <https://gitlab.com/limira-rs/wasm-logger/-/blob/0c16227/src/lib.rs#L72-85>
-pub(crate) struct Style<'s> {
-    pub trace: &'s str,
-    pub debug: &'s str,
-    pub info: &'s str,
-    pub warn: &'s str,
-    pub error: &'s str,
-    pub file_line: &'s str,
-    pub text: &'s str,
+pub(crate) struct Style {
+    pub trace: String,
+    pub debug: String,
+    pub info: String,
+    pub warn: String,
+    pub error: String,
+    pub file_line: String,
+    pub text: String,
+    pub timestamp: String,
+    pub session_id: String,
 }
 
-impl Style<'static> {
+impl Style {
+    /// Returns the level style for the given level.
+    pub fn level(&self, level: log::Level) -> &str {
+        match level {
+            log::Level::Trace => &self.trace,
+            log::Level::Debug => &self.debug,
+            log::Level::Info => &self.info,
+            log::Level::Warn => &self.warn,
+            log::Level::Error => &self.error,
+        }
+    }
+}
+
+impl Default for Style {
     /// Returns default style values.
-    pub const fn default() -> Self {
+    fn default() -> Self {
         macro_rules! bg_color {
             ($color:expr) => {
-                concat!("color: white; padding: 0 3px; background: ", $color, ";")
+                concat!("color: white; padding: 0 3px; background: ", $color, ";").to_string()
             };
-        };
+        }
 
         Style {
             trace: bg_color!("gray"),
@@ -26,8 +41,10 @@ impl Style<'static> {
             info: bg_color!("green"),
             warn: bg_color!("orange"),
             error: bg_color!("darkred"),
-            file_line: "font-weight: bold; color: inherit",
-            text: "background: inherit; color: inherit",
+            file_line: "font-weight: bold; color: inherit".to_string(),
+            text: "background: inherit; color: inherit".to_string(),
+            timestamp: bg_color!("teal"),
+            session_id: bg_color!("purple"),
         }
     }
 }